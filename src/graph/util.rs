@@ -47,6 +47,47 @@ impl DirectedGraph {
         dist
     }
 
+    // Single-source shortest path to a specific target, guided by an
+    // admissible heuristic. Like dijkstra, but the heap is keyed on the
+    // estimated total cost f = g + heuristic(v) while dist tracks the true
+    // cost g, so the search is directed towards dst instead of exploring
+    // uniformly outward.
+    pub fn astar(
+        &self,
+        src: usize,
+        dst: usize,
+        heuristic: impl Fn(usize) -> u64,
+    ) -> Option<(u64, Vec<usize>)> {
+        let mut dist = vec![u64::max_value(); self.num_v()];
+        let mut prev = vec![usize::max_value(); self.num_v()];
+        let mut heap = std::collections::BinaryHeap::new();
+
+        dist[src] = 0;
+        heap.push((Reverse(heuristic(src)), 0, src));
+        while let Some((Reverse(_), g, u)) = heap.pop() {
+            if g != dist[u] {
+                continue;
+            }
+            if u == dst {
+                let mut path = vec![dst];
+                while *path.last().unwrap() != src {
+                    path.push(prev[*path.last().unwrap()]);
+                }
+                path.reverse();
+                return Some((dist[dst], path));
+            }
+            for (e, v) in self.adj_list(u) {
+                let dist_v = g + self.edge_weights[*e] as u64;
+                if dist[*v] > dist_v {
+                    dist[*v] = dist_v;
+                    prev[*v] = u;
+                    heap.push((Reverse(dist_v + heuristic(*v)), dist_v, *v));
+                }
+            }
+        }
+        None
+    }
+
     pub fn dfs(&self, root: usize) -> DfsIterator {
         let mut visited = vec![false; self.num_v()];
         visited[root] = true;
@@ -87,6 +128,281 @@ impl DirectedGraph {
         }
         dist
     }
+
+    // Single-source shortest paths with possibly-negative edge weights. Runs
+    // num_v()-1 relaxation passes over every edge, then does one more pass to
+    // detect a negative cycle reachable from src; if one exists, its vertices
+    // are extracted and returned instead of the distances.
+    pub fn bellman_ford(&self, src: usize) -> Result<Vec<i64>, Vec<usize>> {
+        let numv = self.num_v();
+        let mut dist = vec![i64::MAX; numv];
+        let mut prev = vec![usize::max_value(); numv];
+        dist[src] = 0;
+
+        for _ in 0..numv.saturating_sub(1) {
+            for (idx, edge) in self.edges.iter().enumerate() {
+                let (u, v) = *edge;
+                if dist[u] != i64::MAX && dist[u] + self.edge_weights[idx] < dist[v] {
+                    dist[v] = dist[u] + self.edge_weights[idx];
+                    prev[v] = u;
+                }
+            }
+        }
+
+        let mut cycle_vertex = None;
+        for (idx, edge) in self.edges.iter().enumerate() {
+            let (u, v) = *edge;
+            if dist[u] != i64::MAX && dist[u] + self.edge_weights[idx] < dist[v] {
+                prev[v] = u;
+                cycle_vertex = Some(v);
+                break;
+            }
+        }
+
+        match cycle_vertex {
+            None => Ok(dist),
+            Some(mut v) => {
+                for _ in 0..numv {
+                    v = prev[v];
+                }
+                let mut cycle = vec![v];
+                let mut u = prev[v];
+                while u != v {
+                    cycle.push(u);
+                    u = prev[u];
+                }
+                cycle.reverse();
+                Err(cycle)
+            }
+        }
+    }
+
+    // Costs of the k cheapest src->dst paths (repeated vertices allowed), in
+    // ascending order. Generalizes dijkstra's heap: a vertex is only "done"
+    // once it has been popped k times, so the search keeps exploring past
+    // the first shortest path to find the next-cheapest alternatives.
+    pub fn k_shortest_paths(&self, src: usize, dst: usize, k: usize) -> Vec<u64> {
+        let mut count = vec![0usize; self.num_v()];
+        let mut heap = std::collections::BinaryHeap::new();
+        let mut costs = Vec::with_capacity(k);
+
+        heap.push((Reverse(0u64), src));
+        while let Some((Reverse(c), u)) = heap.pop() {
+            if count[u] >= k {
+                continue;
+            }
+            count[u] += 1;
+            if u == dst {
+                costs.push(c);
+                if count[dst] == k {
+                    break;
+                }
+            }
+            for (e, v) in self.adj_list(u) {
+                if count[*v] < k {
+                    heap.push((Reverse(c + self.edge_weights[*e] as u64), *v));
+                }
+            }
+        }
+        costs
+    }
+
+    /// Every simple (vertex-non-repeating) path from src to dst whose edge
+    /// count falls in `[min_len, max_len]`, found via backtracking DFS.
+    pub fn all_simple_paths(
+        &self,
+        src: usize,
+        dst: usize,
+        min_len: usize,
+        max_len: usize,
+    ) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.num_v()];
+        let mut stack = vec![src];
+        let mut paths = Vec::new();
+
+        visited[src] = true;
+        self.simple_paths_recurse(dst, min_len, max_len, &mut visited, &mut stack, &mut paths);
+        paths
+    }
+
+    fn simple_paths_recurse(
+        &self,
+        dst: usize,
+        min_len: usize,
+        max_len: usize,
+        visited: &mut [bool],
+        stack: &mut Vec<usize>,
+        paths: &mut Vec<Vec<usize>>,
+    ) {
+        let u = *stack.last().unwrap();
+        if u == dst && stack.len() - 1 >= min_len {
+            paths.push(stack.clone());
+        }
+        if stack.len() - 1 >= max_len {
+            return;
+        }
+        for (_, v) in self.adj_list(u) {
+            let v = *v;
+            if !visited[v] {
+                visited[v] = true;
+                stack.push(v);
+                self.simple_paths_recurse(dst, min_len, max_len, visited, stack, paths);
+                stack.pop();
+                visited[v] = false;
+            }
+        }
+    }
+
+    /// Linear vertex ordering consistent with all edges, via Kahn's
+    /// algorithm, or `Err` if the graph contains a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<usize>, ()> {
+        let numv = self.num_v();
+        let mut in_degree = vec![0usize; numv];
+        for edge in &self.edges {
+            in_degree[edge.1] += 1;
+        }
+
+        let mut queue = (0..numv).filter(|&v| in_degree[v] == 0).collect::<Vec<_>>();
+        let mut order = Vec::with_capacity(numv);
+
+        while let Some(u) = queue.pop() {
+            order.push(u);
+            for (_, v) in self.adj_list(u) {
+                let v = *v;
+                in_degree[v] -= 1;
+                if in_degree[v] == 0 {
+                    queue.push(v);
+                }
+            }
+        }
+
+        if order.len() == numv {
+            Ok(order)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Immediate dominator of every vertex reachable from root (root itself
+    /// maps to None). Implements the Cooper-Harvey-Kennedy iterative
+    /// algorithm: a reverse-postorder DFS numbering lets `intersect` walk two
+    /// candidate dominators up the partial dominator tree until they meet.
+    pub fn dominators(&self, root: usize) -> Vec<Option<usize>> {
+        let numv = self.num_v();
+        let mut visited = vec![false; numv];
+        let mut postorder = Vec::new();
+        self.dominators_dfs(root, &mut visited, &mut postorder);
+        postorder.reverse();
+        let rpo = postorder;
+
+        let mut rpo_num = vec![None; numv];
+        for (i, &v) in rpo.iter().enumerate() {
+            rpo_num[v] = Some(i);
+        }
+
+        let mut preds = vec![Vec::new(); numv];
+        for edge in &self.edges {
+            if rpo_num[edge.1].is_some() {
+                preds[edge.1].push(edge.0);
+            }
+        }
+
+        let mut idom = vec![None; numv];
+        idom[root] = Some(root);
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &v in rpo.iter().skip(1) {
+                let mut new_idom = None;
+                for &p in &preds[v] {
+                    if idom[p].is_none() {
+                        continue;
+                    }
+                    new_idom = Some(match new_idom {
+                        None => p,
+                        Some(ni) => Self::intersect(ni, p, &idom, &rpo_num),
+                    });
+                }
+                if idom[v] != new_idom {
+                    idom[v] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+
+        idom[root] = None;
+        idom
+    }
+
+    fn dominators_dfs(&self, u: usize, visited: &mut [bool], postorder: &mut Vec<usize>) {
+        visited[u] = true;
+        for (_, v) in self.adj_list(u) {
+            if !visited[*v] {
+                self.dominators_dfs(*v, visited, postorder);
+            }
+        }
+        postorder.push(u);
+    }
+
+    // Walks a and b up the partial dominator tree by rpo number until they
+    // meet at their common dominator.
+    fn intersect(
+        mut a: usize,
+        mut b: usize,
+        idom: &[Option<usize>],
+        rpo_num: &[Option<usize>],
+    ) -> usize {
+        while a != b {
+            while rpo_num[a] > rpo_num[b] {
+                a = idom[a].unwrap();
+            }
+            while rpo_num[b] > rpo_num[a] {
+                b = idom[b].unwrap();
+            }
+        }
+        a
+    }
+
+    /// Reachability matrix of the graph, computed with a Floyd-Warshall
+    /// style triple loop over a boolean matrix seeded from `self.edges`.
+    pub fn transitive_closure(&self) -> Vec<Vec<bool>> {
+        let numv = self.num_v();
+        let mut reach = vec![vec![false; numv]; numv];
+
+        for edge in &self.edges {
+            reach[edge.0][edge.1] = true;
+        }
+
+        for k in 0..numv {
+            for i in 0..numv {
+                for j in 0..numv {
+                    if reach[i][k] && reach[k][j] {
+                        reach[i][j] = true;
+                    }
+                }
+            }
+        }
+        reach
+    }
+
+    /// The minimal edge set of a DAG with the same reachability relation: an
+    /// edge (u, v) is dropped whenever some other direct successor of u can
+    /// already reach v.
+    pub fn transitive_reduction(&self) -> Vec<(usize, usize)> {
+        let reach = self.transitive_closure();
+
+        self.edges
+            .iter()
+            .copied()
+            .filter(|&(u, v)| {
+                !self
+                    .edges
+                    .iter()
+                    .any(|&(u2, w)| u2 == u && w != v && reach[w][v])
+            })
+            .collect()
+    }
 }
 
 impl UndirectedGraph {
@@ -104,6 +420,40 @@ impl UndirectedGraph {
             })
             .collect()
     }
+
+    /// Kuhn's augmenting-path algorithm for maximum cardinality bipartite
+    /// matching. `left` lists the vertices on one side of the bipartition;
+    /// the result maps each matched vertex, on either side, to its partner.
+    pub fn max_bipartite_matching(&self, left: &[usize]) -> Vec<Option<usize>> {
+        let mut match_to = vec![None; self.num_v()];
+        for &u in left {
+            let mut visited = vec![false; self.num_v()];
+            self.try_augment(u, &mut visited, &mut match_to);
+        }
+        match_to
+    }
+
+    // Looks for an augmenting path starting at the free (or re-matched) left
+    // vertex u, using visited to avoid revisiting right vertices within this
+    // phase.
+    fn try_augment(&self, u: usize, visited: &mut [bool], match_to: &mut [Option<usize>]) -> bool {
+        for (_, v) in self.adj_list(u) {
+            let v = *v;
+            if !visited[v] {
+                visited[v] = true;
+                let can_match = match match_to[v] {
+                    None => true,
+                    Some(w) => self.try_augment(w, visited, match_to),
+                };
+                if can_match {
+                    match_to[u] = Some(v);
+                    match_to[v] = Some(u);
+                    return true;
+                }
+            }
+        }
+        false
+    }
 }
 pub struct DfsIterator<'a> {
     visited: Vec<bool>,
@@ -160,6 +510,27 @@ mod test {
         assert_eq!(mst_cost, 8);
     }
 
+    #[test]
+    fn test_max_bipartite_matching() {
+        // Left side {0, 1, 2}, right side {3, 4, 5}.
+        let mut graph = UndirectedGraph::new(6, 4);
+        graph.add_edge(0, 3);
+        graph.add_edge(0, 4);
+        graph.add_edge(1, 4);
+        graph.add_edge(2, 4);
+
+        let left = [0, 1, 2];
+        let match_to = graph.max_bipartite_matching(&left);
+
+        let matched_pairs = left.iter().filter(|&&u| match_to[u].is_some()).count();
+        assert_eq!(matched_pairs, 2);
+        for &u in &left {
+            if let Some(v) = match_to[u] {
+                assert_eq!(match_to[v], Some(u));
+            }
+        }
+    }
+
     #[test]
     fn test_dijkstra() {
         let mut graph = DirectedGraph::new(3, 3);
@@ -171,6 +542,26 @@ mod test {
         assert_eq!(dist, vec![0, 7, 10]);
     }
 
+    #[test]
+    fn test_astar() {
+        let mut graph = DirectedGraph::new(3, 3);
+        graph.add_weighted_edge(0, 1, 7);
+        graph.add_weighted_edge(1, 2, 3);
+        graph.add_weighted_edge(2, 0, 5);
+
+        let (dist, path) = graph.astar(0, 2, |_| 0).unwrap();
+        assert_eq!(dist, 10);
+        assert_eq!(path, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_astar_unreachable() {
+        let mut graph = DirectedGraph::new(3, 1);
+        graph.add_weighted_edge(0, 1, 1);
+
+        assert_eq!(graph.astar(0, 2, |_| 0), None);
+    }
+
     #[test]
     fn test_dfs() {
         let mut graph = DirectedGraph::new(4, 6);
@@ -233,7 +624,7 @@ mod test {
         assert_eq!(num_v - 1, dfs_check[num_v - 1]);
     }
 
-     #[test]
+    #[test]
     fn test_floyd_warshall() {
         let num_v = 8;
         let mut graph = DirectedGraph::new(num_v, 10);
@@ -252,4 +643,132 @@ mod test {
 
         assert_eq!(dist[0][7], 14i64);
     }
+
+    #[test]
+    fn test_bellman_ford() {
+        let mut graph = DirectedGraph::new(3, 3);
+        graph.add_weighted_edge(0, 1, 7);
+        graph.add_weighted_edge(1, 2, -3);
+        graph.add_weighted_edge(0, 2, 10);
+
+        let dist = graph.bellman_ford(0).unwrap();
+        assert_eq!(dist, vec![0, 7, 4]);
+    }
+
+    #[test]
+    fn test_bellman_ford_negative_cycle() {
+        let mut graph = DirectedGraph::new(3, 3);
+        graph.add_weighted_edge(0, 1, 1);
+        graph.add_weighted_edge(1, 2, -1);
+        graph.add_weighted_edge(2, 1, -1);
+
+        let cycle = graph.bellman_ford(0).unwrap_err();
+        assert_eq!(cycle.len(), 2);
+        assert!(cycle.contains(&1));
+        assert!(cycle.contains(&2));
+    }
+
+    #[test]
+    fn test_k_shortest_paths() {
+        let mut graph = DirectedGraph::new(4, 5);
+        graph.add_weighted_edge(0, 1, 1);
+        graph.add_weighted_edge(0, 2, 2);
+        graph.add_weighted_edge(1, 3, 5);
+        graph.add_weighted_edge(2, 3, 4);
+        graph.add_weighted_edge(0, 3, 9);
+
+        let costs = graph.k_shortest_paths(0, 3, 3);
+        assert_eq!(costs, vec![6, 6, 9]);
+    }
+
+    #[test]
+    fn test_all_simple_paths() {
+        let mut graph = DirectedGraph::new(4, 5);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 3);
+        graph.add_edge(0, 2);
+        graph.add_edge(2, 3);
+        graph.add_edge(0, 3);
+
+        let mut paths = graph.all_simple_paths(0, 3, 0, 10);
+        paths.sort();
+        assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3], vec![0, 3]]);
+
+        let short_paths = graph.all_simple_paths(0, 3, 2, 2);
+        assert!(short_paths.contains(&vec![0, 1, 3]));
+        assert!(short_paths.contains(&vec![0, 2, 3]));
+        assert!(!short_paths.contains(&vec![0, 3]));
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut graph = DirectedGraph::new(4, 4);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 3);
+
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), 4);
+        let pos = |v: usize| order.iter().position(|&x| x == v).unwrap();
+        assert!(pos(0) < pos(1));
+        assert!(pos(0) < pos(2));
+        assert!(pos(1) < pos(3));
+        assert!(pos(2) < pos(3));
+    }
+
+    #[test]
+    fn test_topological_sort_cycle() {
+        let mut graph = DirectedGraph::new(3, 3);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+
+        assert_eq!(graph.topological_sort(), Err(()));
+    }
+
+    #[test]
+    fn test_dominators() {
+        // Classic diamond with a loop back edge: 0 -> 1, 0 -> 2, 1 -> 3, 2 ->
+        // 3, 3 -> 1. Every path to 3 goes through 0, so idom(3) = 0.
+        let mut graph = DirectedGraph::new(4, 5);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 1);
+
+        let idom = graph.dominators(0);
+        assert_eq!(idom, vec![None, Some(0), Some(0), Some(0)]);
+    }
+
+    #[test]
+    fn test_transitive_closure() {
+        let mut graph = DirectedGraph::new(3, 2);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+
+        let reach = graph.transitive_closure();
+        assert_eq!(
+            reach,
+            vec![
+                vec![false, true, true],
+                vec![false, false, true],
+                vec![false, false, false],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transitive_reduction() {
+        // 0 -> 1, 1 -> 2, and the redundant shortcut 0 -> 2.
+        let mut graph = DirectedGraph::new(3, 3);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(0, 2);
+
+        let mut reduction = graph.transitive_reduction();
+        reduction.sort();
+        assert_eq!(reduction, vec![(0, 1), (1, 2)]);
+    }
 }